@@ -1,8 +1,16 @@
 use ndarray::{Array2, Axis};
+use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum LayerActivation {
     ReLU,
-    Softmax
+    Softmax,
+    Sigmoid,
+    Tanh,
+    /** Identity activation, typically used on a regression output layer. */
+    Linear,
+    /** Leaky/parametric ReLU: multiplies negative inputs by the given factor instead of zeroing them. */
+    LeakyReLU(f64)
 }
 
 pub struct ActivationFunction {
@@ -16,12 +24,32 @@ impl ActivationFunction {
         }
     }
 
+    pub fn kind(&self) -> LayerActivation {
+        self.activation
+    }
+
     fn relu(&self, inputs: Array2<f64>) -> Array2<f64> {
         inputs.mapv(|x| x.max(0.0))
     }
 
+    fn sigmoid(&self, inputs: Array2<f64>) -> Array2<f64> {
+        inputs.mapv(|x| 1.0 / (1.0 + (-x).exp()))
+    }
+
+    fn tanh(&self, inputs: Array2<f64>) -> Array2<f64> {
+        inputs.mapv(|x| x.tanh())
+    }
+
+    fn linear(&self, inputs: Array2<f64>) -> Array2<f64> {
+        inputs
+    }
+
+    fn leaky_relu(&self, inputs: Array2<f64>, alpha: f64) -> Array2<f64> {
+        inputs.mapv(|x| if x > 0.0 { x } else { alpha * x })
+    }
+
     /**
-     * Used as the activation function within the output layer for classification models. 
+     * Used as the activation function within the output layer for classification models.
      * Outputs a probability distribution for each row of inputs.
      */
     fn softmax(&self, inputs: Array2<f64>) -> Array2<f64> {
@@ -40,10 +68,91 @@ impl ActivationFunction {
         res
     }
 
+    fn relu_backward(&self, output: Array2<f64>, grad_output: Array2<f64>) -> Array2<f64> {
+        grad_output * output.mapv(|x| if x > 0.0 { 1.0 } else { 0.0 })
+    }
+
+    fn sigmoid_backward(&self, output: Array2<f64>, grad_output: Array2<f64>) -> Array2<f64> {
+        grad_output * output.mapv(|s| s * (1.0 - s))
+    }
+
+    fn tanh_backward(&self, output: Array2<f64>, grad_output: Array2<f64>) -> Array2<f64> {
+        grad_output * output.mapv(|t| 1.0 - t * t)
+    }
+
+    fn linear_backward(&self, grad_output: Array2<f64>) -> Array2<f64> {
+        grad_output
+    }
+
+    fn leaky_relu_backward(&self, output: Array2<f64>, grad_output: Array2<f64>, alpha: f64) -> Array2<f64> {
+        grad_output * output.mapv(|x| if x > 0.0 { 1.0 } else { alpha })
+    }
+
+    /**
+     * Per-row Jacobian-vector product for softmax: grad_input[i] = output[i] * (grad_output[i] - sum_j(grad_output[j] * output[j])).
+     * This is equivalent to multiplying by the full softmax Jacobian but avoids ever materializing it.
+     */
+    fn softmax_backward(&self, output: Array2<f64>, grad_output: Array2<f64>) -> Array2<f64> {
+        let dot = (&grad_output * &output).sum_axis(Axis(1)).insert_axis(Axis(1));
+        output * (grad_output - dot)
+    }
+
     pub fn forward(&self, inputs: Array2<f64>) -> Array2<f64> {
         match self.activation {
             LayerActivation::ReLU => self.relu(inputs),
-            LayerActivation::Softmax => self.softmax(inputs)
+            LayerActivation::Softmax => self.softmax(inputs),
+            LayerActivation::Sigmoid => self.sigmoid(inputs),
+            LayerActivation::Tanh => self.tanh(inputs),
+            LayerActivation::Linear => self.linear(inputs),
+            LayerActivation::LeakyReLU(alpha) => self.leaky_relu(inputs, alpha)
         }
     }
-}
\ No newline at end of file
+
+    /**
+     * Computes the gradient of the loss with respect to this activation's inputs, given the
+     * cached forward output and the incoming gradient from the next layer (or the loss function).
+     */
+    pub fn backward(&self, output: Array2<f64>, grad_output: Array2<f64>) -> Array2<f64> {
+        match self.activation {
+            LayerActivation::ReLU => self.relu_backward(output, grad_output),
+            LayerActivation::Softmax => self.softmax_backward(output, grad_output),
+            LayerActivation::Sigmoid => self.sigmoid_backward(output, grad_output),
+            LayerActivation::Tanh => self.tanh_backward(output, grad_output),
+            LayerActivation::Linear => self.linear_backward(grad_output),
+            LayerActivation::LeakyReLU(alpha) => self.leaky_relu_backward(output, grad_output, alpha)
+        }
+    }
+}
+
+#[cfg(test)]
+mod activation_function_tests {
+    use ndarray::array;
+
+    use super::{ActivationFunction, LayerActivation};
+
+    #[test]
+    fn sigmoid_forward_matches_known_values() {
+        let func = ActivationFunction::new(LayerActivation::Sigmoid);
+        let output = func.forward(array![[0.0]]);
+
+        assert!((output[[0, 0]] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn leaky_relu_scales_negative_inputs() {
+        let func = ActivationFunction::new(LayerActivation::LeakyReLU(0.1));
+        let output = func.forward(array![[-2.0, 3.0]]);
+
+        assert!((output[[0, 0]] - -0.2).abs() < 1e-9);
+        assert_eq!(output[[0, 1]], 3.0);
+    }
+
+    #[test]
+    fn linear_backward_is_the_identity() {
+        let func = ActivationFunction::new(LayerActivation::Linear);
+        let grad_output = array![[1.0, -2.0]];
+        let output = func.backward(array![[5.0, 5.0]], grad_output.clone());
+
+        assert_eq!(output, grad_output);
+    }
+}
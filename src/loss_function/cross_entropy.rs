@@ -54,6 +54,31 @@ impl CrossEntropy {
         let correct_confidences = self.determine_correct_confidences_sparse(outputs, target_output_set);
         self.cross_entropy(correct_confidences)
     }
+
+    /**
+     * Gradient of the mean cross-entropy loss with respect to the (clipped) softmax outputs:
+     * `-target / clipped_pred / batch_size`.
+     */
+    fn cross_entropy_backward_one_hot(&self, outputs: Array2<f64>, target_output_set: Array2<f64>) -> Array2<f64> {
+        let batch_size = outputs.dim().0 as f64;
+        -target_output_set / self.clip_values(outputs) / batch_size
+    }
+
+    /**
+     * Sparse counterpart of `cross_entropy_backward_one_hot`: only the correct-class entry of
+     * each row is non-zero, set to `-1 / clipped_pred / batch_size`.
+     */
+    fn cross_entropy_backward_sparse(&self, outputs: Array2<f64>, target_output_set: Array1<usize>) -> Array2<f64> {
+        let clipped = self.clip_values(outputs);
+        let batch_size = clipped.dim().0 as f64;
+        let mut grad = Array2::<f64>::zeros(clipped.dim());
+
+        for (row, &class) in target_output_set.iter().enumerate() {
+            grad[[row, class]] = -1.0 / clipped[[row, class]] / batch_size;
+        }
+
+        grad
+    }
 }
 
 impl LossCalculation for CrossEntropy {
@@ -64,6 +89,71 @@ impl LossCalculation for CrossEntropy {
     fn forward_onehot(&self, outputs: Array2<f64>, target_outputs: Array2<f64>) -> Array1<f64> {
         self.cross_entropy_one_hot(self.clip_values(outputs), target_outputs)
     }
+
+    fn backward_onehot(&self, outputs: Array2<f64>, target_outputs: Array2<f64>) -> Array2<f64> {
+        self.cross_entropy_backward_one_hot(outputs, target_outputs)
+    }
+
+    fn backward_sparse(&self, outputs: Array2<f64>, target_outputs: Array1<usize>) -> Array2<f64> {
+        self.cross_entropy_backward_sparse(outputs, target_outputs)
+    }
+}
+
+/**
+ * Fused gradient for a softmax output layer trained with cross-entropy loss. When the last
+ * activation is softmax and the loss is cross-entropy, the softmax and loss Jacobians collapse
+ * into `(predicted - target) / batch_size`, which is both cheaper and more numerically stable
+ * than composing `ActivationFunction::backward` with the plain cross-entropy gradient.
+ */
+pub fn softmax_cross_entropy_backward_one_hot(predicted: Array2<f64>, target_output_set: Array2<f64>) -> Array2<f64> {
+    let batch_size = predicted.dim().0 as f64;
+    (predicted - target_output_set) / batch_size
+}
+
+/**
+ * Sparse counterpart of `softmax_cross_entropy_backward_one_hot`: subtracts 1.0 from the
+ * predicted probability at each sample's correct-class index before scaling by the batch size.
+ */
+pub fn softmax_cross_entropy_backward_sparse(predicted: Array2<f64>, target_output_set: Array1<usize>) -> Array2<f64> {
+    let batch_size = predicted.dim().0 as f64;
+    let mut grad = predicted;
+
+    for (row, &class) in target_output_set.iter().enumerate() {
+        grad[[row, class]] -= 1.0;
+    }
+
+    grad / batch_size
+}
+
+#[cfg(test)]
+mod cross_entropy_backward_tests {
+    use ndarray::array;
+
+    use super::{CrossEntropy, LossCalculation};
+
+    #[test]
+    fn backward_onehot_matches_negative_target_over_clipped_pred() {
+        let outputs = array![[0.2, 0.8]];
+        let targets = array![[0.0, 1.0]];
+
+        let grad = CrossEntropy::new().backward_onehot(outputs, targets);
+
+        // -target / clipped_pred / batch_size, batch_size == 1 here
+        assert!((grad[[0, 0]] - 0.0).abs() < 1e-9);
+        assert!((grad[[0, 1]] - (-1.25)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn backward_sparse_matches_backward_onehot_for_the_same_targets() {
+        let outputs = array![[0.2, 0.8], [0.6, 0.4]];
+        let onehot_targets = array![[0.0, 1.0], [1.0, 0.0]];
+        let sparse_targets = array![1, 0];
+
+        let onehot_grad = CrossEntropy::new().backward_onehot(outputs.clone(), onehot_targets);
+        let sparse_grad = CrossEntropy::new().backward_sparse(outputs, sparse_targets);
+
+        assert_eq!(onehot_grad, sparse_grad);
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,13 @@
 pub mod cross_entropy;
 
 use cross_entropy::CrossEntropy;
-use ndarray::{Array1, Array2};
+use ndarray::{s, Array1, Array2, Axis};
 
 pub trait LossCalculation {
     fn forward_onehot(&self, outputs: Array2<f64>, target_outputs: Array2<f64>) -> Array1<f64>;
     fn forward_sparse(&self, outputs: Array2<f64>, target_outputs: Array1<usize>) -> Array1<f64>;
+    fn backward_onehot(&self, outputs: Array2<f64>, target_outputs: Array2<f64>) -> Array2<f64>;
+    fn backward_sparse(&self, outputs: Array2<f64>, target_outputs: Array1<usize>) -> Array2<f64>;
 }
 
 pub trait LossTargetData {
@@ -14,8 +16,21 @@ pub trait LossTargetData {
     fn encoding(&self) -> LossFunctionTargetEncoding;
     fn get_onehot(&self) -> Array2<f64>;
     fn get_sparse(&self) -> Array1<usize>;
+
+    /**
+     * Reorders the target rows to match `indices`, e.g. for a per-epoch shuffle. Mirrors
+     * `ndarray`'s `select(Axis(0), indices)` for whichever encoding this target data holds.
+     */
+    fn select_rows(&self, indices: &[usize]) -> Self;
+
+    /**
+     * Slices out the `[start, end)` rows, e.g. for a mini-batch. Mirrors `ndarray`'s
+     * `slice(s![start..end, ..])` for whichever encoding this target data holds.
+     */
+    fn slice_rows(&self, start: usize, end: usize) -> Self;
 }
 
+#[derive(Clone)]
 pub struct OneHotLossTargetData {
     data: Array2<f64>
 }
@@ -42,8 +57,17 @@ impl LossTargetData for OneHotLossTargetData {
     fn get_sparse(&self) -> Array1<usize> {
         panic!("Attempted to get sparse target data from struct configured for one-hot");
     }
+
+    fn select_rows(&self, indices: &[usize]) -> OneHotLossTargetData {
+        OneHotLossTargetData::new_onehot(self.data.select(Axis(0), indices))
+    }
+
+    fn slice_rows(&self, start: usize, end: usize) -> OneHotLossTargetData {
+        OneHotLossTargetData::new_onehot(self.data.slice(s![start..end, ..]).to_owned())
+    }
 }
 
+#[derive(Clone)]
 pub struct SparseLossTargetData {
     data: Array1<usize>
 }
@@ -70,8 +94,17 @@ impl LossTargetData for SparseLossTargetData {
     fn get_sparse(&self) -> Array1<usize> {
         self.data.clone()
     }
+
+    fn select_rows(&self, indices: &[usize]) -> SparseLossTargetData {
+        SparseLossTargetData::new_sparse(self.data.select(Axis(0), indices))
+    }
+
+    fn slice_rows(&self, start: usize, end: usize) -> SparseLossTargetData {
+        SparseLossTargetData::new_sparse(self.data.slice(s![start..end]).to_owned())
+    }
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum LossFunctionType {
     CrossEntropy
 }
@@ -82,7 +115,8 @@ pub enum LossFunctionTargetEncoding {
 }
 
 pub struct LossFunction {
-    function: Box<dyn LossCalculation>
+    function: Box<dyn LossCalculation>,
+    function_type: LossFunctionType
 }
 
 impl LossFunction {
@@ -90,11 +124,16 @@ impl LossFunction {
         LossFunction {
             function: match function_type {
                 LossFunctionType::CrossEntropy => Box::new(CrossEntropy::new()) as Box<dyn LossCalculation>
-            }
+            },
+            function_type
         }
     }
 
-    pub fn calculate<T>(&self, outputs: Array2<f64>, target_outputs: T) -> f64 
+    pub fn function_type(&self) -> LossFunctionType {
+        self.function_type
+    }
+
+    pub fn calculate<T>(&self, outputs: Array2<f64>, target_outputs: T) -> f64
     where
         T: LossTargetData,
     {
@@ -107,4 +146,20 @@ impl LossFunction {
             }
         }
     }
+
+    /**
+     * Gradient of the mean loss with respect to this loss function's inputs, dispatched on the
+     * target encoding. `Sequential::propagate_loss_gradient` does not use this for a softmax output
+     * layer; it calls the fused `cross_entropy::softmax_cross_entropy_backward_one_hot`/`_sparse`
+     * functions instead.
+     */
+    pub fn backward<T>(&self, outputs: Array2<f64>, target_outputs: T) -> Array2<f64>
+    where
+        T: LossTargetData,
+    {
+        match target_outputs.encoding() {
+            LossFunctionTargetEncoding::OneHot => self.function.backward_onehot(outputs, target_outputs.get_onehot()),
+            LossFunctionTargetEncoding::Sparse => self.function.backward_sparse(outputs, target_outputs.get_sparse())
+        }
+    }
 }
\ No newline at end of file
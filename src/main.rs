@@ -1,10 +1,17 @@
 use std::fs;
 
 use ndarray::{prelude::*, stack, OwnedRepr};
+mod activation_function;
 mod layer;
+mod loss_function;
+mod optimizer;
+mod sequential;
 
-use layer::Layer;
+use activation_function::LayerActivation;
+use loss_function::{LossFunction, LossFunctionType};
 use ndarray_rand::{rand_distr::{num_traits::Float, Normal}, RandomExt};
+use optimizer::Sgd;
+use sequential::{DenseLayer, DropoutLayer, Sequential};
 use charts::{Chart, ScaleBand, ScaleLinear, ScatterView, VerticalBarView};
 
 #[derive(Debug)]
@@ -70,15 +77,13 @@ fn main() {
     let dataset_arr = arr2(&dataset.iter().map(|item| {
         [item.x, item.y]
     }).collect::<Vec<[f64; 2]>>());
+    let dataset_classes = Array1::from(dataset.iter().map(|item| item.class as usize).collect::<Vec<usize>>());
 
-    let dense_1 = Layer::<3,2>::new(layer::LayerActivation::ReLU);
-    let dense_2 = Layer::<3,3>::new(layer::LayerActivation::Softmax);
+    let mut network = Sequential::new(Box::new(Sgd::new(0.1).with_weight_decay(0.001)), LossFunction::new(LossFunctionType::CrossEntropy));
+    network.add(Box::new(DenseLayer::new(2, 3, LayerActivation::ReLU)));
+    network.add(Box::new(DropoutLayer::new(0.1)));
+    network.add(Box::new(DenseLayer::new(3, 3, LayerActivation::Softmax)));
+    network.on_epoch(|epoch, loss| println!("epoch {epoch}: loss = {loss}"));
 
-    let dense_1_outputs = dense_1.forward(dataset_arr);
-
-    println!("{:#?}", dense_1_outputs.slice(s![0..5, ..]));
-
-    let dense_2_outputs = dense_2.forward(dense_1_outputs);
-
-    println!("{:#?}", dense_2_outputs.slice(s![0..5, ..]));
+    network.fit(dataset_arr, dataset_classes, 100, 32);
 }
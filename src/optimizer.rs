@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use ndarray::{Array1, Array2};
+
+/**
+ * Applies gradients computed by `Layer::backward` to a layer's weights and biases. `param_id`
+ * identifies the parameter set being updated so a single `Optimizer` can be shared across
+ * multiple layers of different shapes while keeping separate per-parameter moment state (used by
+ * `Adam`); optimizers that don't need per-parameter state, like `Sgd`, can ignore it.
+ */
+pub trait Optimizer {
+    fn update(&mut self, param_id: usize, weights: &mut Array2<f64>, biases: &mut Array1<f64>, weight_grads: &Array2<f64>, bias_grads: &Array1<f64>);
+}
+
+pub struct Sgd {
+    pub learning_rate: f64,
+    pub weight_decay: f64
+}
+
+impl Sgd {
+    pub fn new(learning_rate: f64) -> Sgd {
+        Sgd { learning_rate, weight_decay: 0.0 }
+    }
+
+    /**
+     * Adds L2 weight decay: on every update, weights are additionally pulled towards zero by
+     * `learning_rate * weight_decay * weights`. Biases are left undecayed.
+     */
+    pub fn with_weight_decay(mut self, weight_decay: f64) -> Sgd {
+        self.weight_decay = weight_decay;
+        self
+    }
+}
+
+impl Optimizer for Sgd {
+    fn update(&mut self, _param_id: usize, weights: &mut Array2<f64>, biases: &mut Array1<f64>, weight_grads: &Array2<f64>, bias_grads: &Array1<f64>) {
+        *weights -= &(&*weights * (self.learning_rate * self.weight_decay));
+        *weights -= &(weight_grads * self.learning_rate);
+        *biases -= &(bias_grads * self.learning_rate);
+    }
+}
+
+struct AdamMoments {
+    m_weights: Array2<f64>,
+    v_weights: Array2<f64>,
+    m_biases: Array1<f64>,
+    v_biases: Array1<f64>,
+    t: i32
+}
+
+impl AdamMoments {
+    fn zeros(weights_dim: (usize, usize), biases_dim: usize) -> AdamMoments {
+        AdamMoments {
+            m_weights: Array2::zeros(weights_dim),
+            v_weights: Array2::zeros(weights_dim),
+            m_biases: Array1::zeros(biases_dim),
+            v_biases: Array1::zeros(biases_dim),
+            t: 0
+        }
+    }
+}
+
+pub struct Adam {
+    pub learning_rate: f64,
+    pub beta1: f64,
+    pub beta2: f64,
+    pub epsilon: f64,
+    pub weight_decay: f64,
+    moments: HashMap<usize, AdamMoments>
+}
+
+impl Adam {
+    pub fn new(learning_rate: f64) -> Adam {
+        Adam {
+            learning_rate,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            weight_decay: 0.0,
+            moments: HashMap::new()
+        }
+    }
+
+    /**
+     * Adds L2 weight decay: on every update, weights are additionally pulled towards zero by
+     * `learning_rate * weight_decay * weights`. Biases are left undecayed.
+     */
+    pub fn with_weight_decay(mut self, weight_decay: f64) -> Adam {
+        self.weight_decay = weight_decay;
+        self
+    }
+}
+
+impl Optimizer for Adam {
+    fn update(&mut self, param_id: usize, weights: &mut Array2<f64>, biases: &mut Array1<f64>, weight_grads: &Array2<f64>, bias_grads: &Array1<f64>) {
+        let moments = self.moments
+            .entry(param_id)
+            .or_insert_with(|| AdamMoments::zeros(weights.dim(), biases.dim()));
+
+        moments.t += 1;
+        let t = moments.t;
+
+        moments.m_weights = self.beta1 * &moments.m_weights + (1.0 - self.beta1) * weight_grads;
+        moments.v_weights = self.beta2 * &moments.v_weights + (1.0 - self.beta2) * weight_grads.mapv(|g| g * g);
+        moments.m_biases = self.beta1 * &moments.m_biases + (1.0 - self.beta1) * bias_grads;
+        moments.v_biases = self.beta2 * &moments.v_biases + (1.0 - self.beta2) * bias_grads.mapv(|g| g * g);
+
+        let bias_correction1 = 1.0 - self.beta1.powi(t);
+        let bias_correction2 = 1.0 - self.beta2.powi(t);
+
+        let m_hat_weights = &moments.m_weights / bias_correction1;
+        let v_hat_weights = &moments.v_weights / bias_correction2;
+        let m_hat_biases = &moments.m_biases / bias_correction1;
+        let v_hat_biases = &moments.v_biases / bias_correction2;
+
+        *weights -= &(&*weights * (self.learning_rate * self.weight_decay));
+        *weights -= &(self.learning_rate * &m_hat_weights / (v_hat_weights.mapv(f64::sqrt) + self.epsilon));
+        *biases -= &(self.learning_rate * &m_hat_biases / (v_hat_biases.mapv(f64::sqrt) + self.epsilon));
+    }
+}
+
+#[cfg(test)]
+mod optimizer_tests {
+    use ndarray::array;
+
+    use super::{Adam, Optimizer, Sgd};
+
+    #[test]
+    fn sgd_moves_weights_against_the_gradient() {
+        let mut weights = array![[1.0, 2.0]];
+        let mut biases = array![0.5];
+        let weight_grads = array![[1.0, 1.0]];
+        let bias_grads = array![1.0];
+
+        let mut sgd = Sgd::new(0.1);
+        sgd.update(0, &mut weights, &mut biases, &weight_grads, &bias_grads);
+
+        assert_eq!(weights, array![[0.9, 1.9]]);
+        assert_eq!(biases, array![0.4]);
+    }
+
+    #[test]
+    fn adam_keeps_separate_moments_per_param_id() {
+        let mut weights_a = array![[1.0]];
+        let mut biases_a = array![0.0];
+        let mut weights_b = array![[1.0]];
+        let mut biases_b = array![0.0];
+
+        let grads = array![[1.0]];
+        let bias_grads = array![1.0];
+
+        let mut adam = Adam::new(0.01);
+        adam.update(0, &mut weights_a, &mut biases_a, &grads, &bias_grads);
+        adam.update(1, &mut weights_b, &mut biases_b, &grads, &bias_grads);
+        adam.update(0, &mut weights_a, &mut biases_a, &grads, &bias_grads);
+
+        // param 0 has taken two steps while param 1 has taken one, so they must have diverged
+        assert_ne!(weights_a, weights_b);
+    }
+
+    #[test]
+    fn sgd_weight_decay_shrinks_weights_even_without_gradient() {
+        let mut weights = array![[2.0, -4.0]];
+        let mut biases = array![0.5];
+        let weight_grads = array![[0.0, 0.0]];
+        let bias_grads = array![0.0];
+
+        let mut sgd = Sgd::new(0.1).with_weight_decay(0.5);
+        sgd.update(0, &mut weights, &mut biases, &weight_grads, &bias_grads);
+
+        assert_eq!(weights, array![[1.9, -3.8]]);
+        assert_eq!(biases, array![0.5]);
+    }
+
+    #[test]
+    fn sgd_weight_decay_uses_the_pre_update_weights() {
+        let mut weights = array![[2.0]];
+        let mut biases = array![0.0];
+        let weight_grads = array![[3.0]];
+        let bias_grads = array![0.0];
+
+        let mut sgd = Sgd::new(0.1).with_weight_decay(0.5);
+        sgd.update(0, &mut weights, &mut biases, &weight_grads, &bias_grads);
+
+        // decay is computed off the weight before the gradient step: 2.0 - 0.1*0.5*2.0 - 0.1*3.0 = 1.6
+        assert!((weights[[0, 0]] - 1.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn adam_weight_decay_shrinks_weights() {
+        let mut decayed_weights = array![[1.0]];
+        let mut decayed_biases = array![0.0];
+        let mut plain_weights = array![[1.0]];
+        let mut plain_biases = array![0.0];
+
+        let grads = array![[0.0]];
+        let bias_grads = array![0.0];
+
+        let mut decayed = Adam::new(0.01).with_weight_decay(0.1);
+        let mut plain = Adam::new(0.01);
+
+        decayed.update(0, &mut decayed_weights, &mut decayed_biases, &grads, &bias_grads);
+        plain.update(0, &mut plain_weights, &mut plain_biases, &grads, &bias_grads);
+
+        assert!(decayed_weights[[0, 0]] < plain_weights[[0, 0]]);
+    }
+}
@@ -0,0 +1,622 @@
+use ndarray::prelude::*;
+use ndarray_rand::{rand::{seq::SliceRandom, thread_rng, Rng}, rand_distr::Normal, RandomExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    activation_function::{ActivationFunction, LayerActivation},
+    layer::LayerArrayError,
+    loss_function::{
+        cross_entropy::{softmax_cross_entropy_backward_one_hot, softmax_cross_entropy_backward_sparse},
+        LossFunction, LossFunctionTargetEncoding, LossFunctionType, LossTargetData, OneHotLossTargetData, SparseLossTargetData
+    },
+    optimizer::Optimizer
+};
+
+/**
+ * Controls how a `DenseLayer`'s weights are randomly initialized. `Xavier`/`Glorot` and
+ * `He`/`Kaiming` scale a standard normal draw by the fan-in only (std `sqrt(1/fan_in)` and
+ * `sqrt(2/fan_in)` respectively) so that activations don't vanish or explode as layers are
+ * stacked; `Scaled` reproduces the original
+ * hard-coded `0.01` scaling factor.
+ */
+#[derive(Debug, Clone, Copy)]
+pub enum Initialization {
+    Xavier,
+    He,
+    Scaled(f64)
+}
+
+impl Default for Initialization {
+    fn default() -> Initialization {
+        Initialization::Scaled(0.01)
+    }
+}
+
+/**
+ * The serialized form of a single `Layer`, as written by `Sequential::save`. Each concrete layer
+ * type gets its own variant so layers with no trainable parameters (e.g. `Dropout`) can be
+ * round-tripped without weights/biases.
+ */
+#[derive(Serialize, Deserialize)]
+pub enum LayerSnapshot {
+    Dense {
+        fan_in: usize,
+        neurons: usize,
+        activation: LayerActivation,
+        weights: Vec<Vec<f64>>,
+        biases: Vec<f64>
+    },
+    Dropout {
+        rate: f64
+    }
+}
+
+/**
+ * A layer's trainable weights/biases alongside the gradients computed by the most recent
+ * `backward` call, as returned by `Layer::params_mut`.
+ */
+pub struct LayerParams<'a> {
+    pub weights: &'a mut Array2<f64>,
+    pub biases: &'a mut Array1<f64>,
+    pub weight_grad: &'a Array2<f64>,
+    pub bias_grad: &'a Array1<f64>
+}
+
+/**
+ * A layer that can be composed into a `Sequential` network. Trait objects implementing `Layer`
+ * can be stored heterogeneously in a single `Vec<Box<dyn Layer>>`, which is what lets `Sequential`
+ * hold an arbitrary stack of layers.
+ */
+pub trait Layer {
+    fn forward(&mut self, inputs: Array2<f64>, training: bool) -> Array2<f64>;
+    fn backward(&mut self, grad_output: Array2<f64>) -> Array2<f64>;
+
+    /**
+     * This layer's activation, if it has one. Lets `Sequential` detect when the output layer is
+     * softmax, so it can take the fused softmax+cross-entropy shortcut in `backward_fused_loss`.
+     */
+    fn activation_kind(&self) -> Option<LayerActivation> {
+        None
+    }
+
+    /**
+     * Backward pass for an output layer, given the gradient with respect to this layer's
+     * pre-activation output rather than its post-activation output (e.g. the fused softmax+
+     * cross-entropy shortcut, which differentiates the activation and loss together). Defaults to
+     * treating `grad_pre_activation` as a normal output gradient; layers whose `backward` composes
+     * an activation derivative should override this to skip that step.
+     */
+    fn backward_fused_loss(&mut self, grad_pre_activation: Array2<f64>) -> Array2<f64> {
+        self.backward(grad_pre_activation)
+    }
+
+    /**
+     * Returns this layer's trainable weights/biases alongside the gradients computed by the most
+     * recent `backward` call, or `None` for layers with no trainable parameters (e.g. `Dropout`).
+     */
+    fn params_mut(&mut self) -> Option<LayerParams>;
+
+    fn snapshot(&self) -> LayerSnapshot;
+    fn load_snapshot(&mut self, snapshot: LayerSnapshot) -> Result<(), LayerArrayError>;
+}
+
+/**
+ * A fully-connected layer: `outputs = activation(inputs . weights + biases)`.
+ */
+pub struct DenseLayer {
+    weights: Array2<f64>,
+    biases: Array1<f64>,
+    activation: ActivationFunction,
+    input_cache: Option<Array2<f64>>,
+    output_cache: Option<Array2<f64>>,
+    weight_grad: Option<Array2<f64>>,
+    bias_grad: Option<Array1<f64>>
+}
+
+impl DenseLayer {
+    pub fn new(fan_in: usize, neurons: usize, activation: LayerActivation) -> DenseLayer {
+        DenseLayer::with_init(fan_in, neurons, activation, Initialization::default())
+    }
+
+    /**
+     * Like `new`, but with an explicit `Initialization` instead of the default `0.01`-scaled
+     * draw. Use `Initialization::Xavier`/`He` on deeper stacks to keep activations from
+     * vanishing or exploding as layers are added.
+     */
+    pub fn with_init(fan_in: usize, neurons: usize, activation: LayerActivation, init: Initialization) -> DenseLayer {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let scale = match init {
+            Initialization::Scaled(factor) => factor,
+            Initialization::Xavier => (1.0 / fan_in as f64).sqrt(),
+            Initialization::He => (2.0 / fan_in as f64).sqrt()
+        };
+
+        DenseLayer {
+            weights: scale * Array::random((fan_in, neurons), normal),
+            biases: Array::zeros(neurons),
+            activation: ActivationFunction::new(activation),
+            input_cache: None,
+            output_cache: None,
+            weight_grad: None,
+            bias_grad: None
+        }
+    }
+
+    fn backward_from_pre_activation_grad(&mut self, pre_activation_grad: Array2<f64>) -> Array2<f64> {
+        let input = self.input_cache.clone().expect("forward must be called before backward");
+
+        let grad_input = pre_activation_grad.dot(&self.weights.t());
+        self.weight_grad = Some(input.t().dot(&pre_activation_grad));
+        self.bias_grad = Some(pre_activation_grad.sum_axis(Axis(0)));
+
+        grad_input
+    }
+}
+
+impl Layer for DenseLayer {
+    fn forward(&mut self, inputs: Array2<f64>, _training: bool) -> Array2<f64> {
+        self.input_cache = Some(inputs.clone());
+        let intermediate_output = inputs.dot(&self.weights) + &self.biases;
+        let output = self.activation.forward(intermediate_output);
+        self.output_cache = Some(output.clone());
+        output
+    }
+
+    fn backward(&mut self, grad_output: Array2<f64>) -> Array2<f64> {
+        let output = self.output_cache.clone().expect("forward must be called before backward");
+        let pre_activation_grad = self.activation.backward(output, grad_output);
+        self.backward_from_pre_activation_grad(pre_activation_grad)
+    }
+
+    fn activation_kind(&self) -> Option<LayerActivation> {
+        Some(self.activation.kind())
+    }
+
+    fn backward_fused_loss(&mut self, grad_pre_activation: Array2<f64>) -> Array2<f64> {
+        self.backward_from_pre_activation_grad(grad_pre_activation)
+    }
+
+    fn params_mut(&mut self) -> Option<LayerParams> {
+        Some(LayerParams {
+            weights: &mut self.weights,
+            biases: &mut self.biases,
+            weight_grad: self.weight_grad.as_ref().expect("backward must be called before params_mut"),
+            bias_grad: self.bias_grad.as_ref().expect("backward must be called before params_mut")
+        })
+    }
+
+    fn snapshot(&self) -> LayerSnapshot {
+        let (fan_in, neurons) = self.weights.dim();
+
+        LayerSnapshot::Dense {
+            fan_in,
+            neurons,
+            activation: self.activation.kind(),
+            weights: self.weights.outer_iter().map(|row| row.to_vec()).collect(),
+            biases: self.biases.to_vec()
+        }
+    }
+
+    fn load_snapshot(&mut self, snapshot: LayerSnapshot) -> Result<(), LayerArrayError> {
+        let (fan_in, neurons, activation, weights, biases) = match snapshot {
+            LayerSnapshot::Dense { fan_in, neurons, activation, weights, biases } => (fan_in, neurons, activation, weights, biases),
+            LayerSnapshot::Dropout { .. } => return Err(LayerArrayError::IncorrectDimension(
+                "Expected a Dense layer snapshot, but found a Dropout layer snapshot.".to_string()
+            ))
+        };
+
+        let (current_fan_in, current_neurons) = self.weights.dim();
+
+        if fan_in != current_fan_in || neurons != current_neurons {
+            return Err(LayerArrayError::IncorrectDimension(
+                format!(
+                    "Layer expects weights of shape ({}, {}), but the snapshot has shape ({}, {}).",
+                    current_fan_in, current_neurons, fan_in, neurons
+                ).to_string()
+            ));
+        }
+
+        let flattened_weights: Vec<f64> = weights.into_iter().flatten().collect();
+
+        if flattened_weights.len() != fan_in * neurons {
+            return Err(LayerArrayError::IncorrectDimension(
+                format!(
+                    "Snapshot declares weights of shape ({}, {}), but holds {} values.",
+                    fan_in, neurons, flattened_weights.len()
+                )
+            ));
+        }
+
+        self.weights = Array2::from_shape_vec((fan_in, neurons), flattened_weights)
+            .expect("snapshot weight dimensions were already validated above");
+        self.biases = Array1::from(biases);
+        self.activation = ActivationFunction::new(activation);
+
+        Ok(())
+    }
+}
+
+/**
+ * Inverted dropout: during training, zeroes each activation independently with probability
+ * `rate` and scales survivors by `1 / (1 - rate)` so the expected activation magnitude is
+ * unchanged; at inference it is a no-op. Has no trainable parameters.
+ */
+pub struct DropoutLayer {
+    rate: f64,
+    mask: Option<Array2<f64>>
+}
+
+impl DropoutLayer {
+    pub fn new(rate: f64) -> DropoutLayer {
+        DropoutLayer { rate, mask: None }
+    }
+}
+
+impl Layer for DropoutLayer {
+    fn forward(&mut self, inputs: Array2<f64>, training: bool) -> Array2<f64> {
+        if !training {
+            return inputs;
+        }
+
+        let keep_prob = 1.0 - self.rate;
+        let mut rng = thread_rng();
+        let mask = inputs.mapv(|_| if rng.gen::<f64>() < keep_prob { 1.0 / keep_prob } else { 0.0 });
+        let output = &inputs * &mask;
+        self.mask = Some(mask);
+
+        output
+    }
+
+    fn backward(&mut self, grad_output: Array2<f64>) -> Array2<f64> {
+        let mask = self.mask.clone().expect("forward must be called with training=true before backward");
+        grad_output * mask
+    }
+
+    fn params_mut(&mut self) -> Option<LayerParams> {
+        None
+    }
+
+    fn snapshot(&self) -> LayerSnapshot {
+        LayerSnapshot::Dropout { rate: self.rate }
+    }
+
+    fn load_snapshot(&mut self, snapshot: LayerSnapshot) -> Result<(), LayerArrayError> {
+        match snapshot {
+            LayerSnapshot::Dropout { rate } => {
+                self.rate = rate;
+                Ok(())
+            },
+            LayerSnapshot::Dense { .. } => Err(LayerArrayError::IncorrectDimension(
+                "Expected a Dropout layer snapshot, but found a Dense layer snapshot.".to_string()
+            ))
+        }
+    }
+}
+
+/**
+ * Composes a stack of `Layer`s, an `Optimizer`, and a `LossFunction` into a trainable network and
+ * runs the mini-batch gradient descent training loop.
+ */
+pub struct Sequential {
+    layers: Vec<Box<dyn Layer>>,
+    optimizer: Box<dyn Optimizer>,
+    loss: LossFunction,
+    on_epoch: Option<Box<dyn FnMut(usize, f64)>>,
+    on_error: Option<Box<dyn FnMut(f64)>>
+}
+
+impl Sequential {
+    pub fn new(optimizer: Box<dyn Optimizer>, loss: LossFunction) -> Sequential {
+        Sequential {
+            layers: Vec::new(),
+            optimizer,
+            loss,
+            on_epoch: None,
+            on_error: None
+        }
+    }
+
+    pub fn add(&mut self, layer: Box<dyn Layer>) -> &mut Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /**
+     * Called at the end of each epoch with the epoch index and the mean loss over its batches.
+     */
+    pub fn on_epoch(&mut self, callback: impl FnMut(usize, f64) + 'static) -> &mut Self {
+        self.on_epoch = Some(Box::new(callback));
+        self
+    }
+
+    /**
+     * Called whenever a batch produces a non-finite loss, so callers can log/abort runaway
+     * training instead of it failing silently.
+     */
+    pub fn on_error(&mut self, callback: impl FnMut(f64) + 'static) -> &mut Self {
+        self.on_error = Some(Box::new(callback));
+        self
+    }
+
+    fn forward(&mut self, inputs: Array2<f64>, training: bool) -> Array2<f64> {
+        self.layers.iter_mut().fold(inputs, |acc, layer| layer.forward(acc, training))
+    }
+
+    /**
+     * Backpropagates the loss gradient through every layer. When the output layer is softmax and
+     * the configured loss is cross-entropy, takes the fused `(predicted - target) / batch_size`
+     * shortcut via `Layer::backward_fused_loss` instead of composing the loss gradient with the
+     * softmax Jacobian.
+     */
+    fn propagate_loss_gradient<T: LossTargetData>(&mut self, predicted: Array2<f64>, targets: T) {
+        let use_fused_softmax_cross_entropy = self.loss.function_type() == LossFunctionType::CrossEntropy
+            && self.layers.last().and_then(|layer| layer.activation_kind()) == Some(LayerActivation::Softmax);
+
+        let mut layers = self.layers.iter_mut().rev();
+        let last_layer = match layers.next() {
+            Some(layer) => layer,
+            None => return
+        };
+
+        let mut grad = if use_fused_softmax_cross_entropy {
+            match targets.encoding() {
+                LossFunctionTargetEncoding::OneHot => last_layer.backward_fused_loss(softmax_cross_entropy_backward_one_hot(predicted, targets.get_onehot())),
+                LossFunctionTargetEncoding::Sparse => last_layer.backward_fused_loss(softmax_cross_entropy_backward_sparse(predicted, targets.get_sparse()))
+            }
+        } else {
+            last_layer.backward(self.loss.backward(predicted, targets))
+        };
+
+        // the gradient returned by the first layer has no earlier layer to propagate to, so it is discarded
+        for layer in layers {
+            grad = layer.backward(grad);
+        }
+    }
+
+    fn apply_gradients(&mut self) {
+        for (param_id, layer) in self.layers.iter_mut().enumerate() {
+            if let Some(params) = layer.params_mut() {
+                self.optimizer.update(param_id, params.weights, params.biases, params.weight_grad, params.bias_grad);
+            }
+        }
+    }
+
+    /**
+     * Shared training loop behind `fit`/`fit_onehot`: shuffles the rows each epoch and runs
+     * mini-batches of `batch_size` samples, dispatching the loss calculation and gradient
+     * propagation through whichever `LossTargetData` encoding `targets` holds.
+     */
+    fn fit_with_target_data<T: LossTargetData + Clone>(&mut self, inputs: Array2<f64>, targets: T, epochs: usize, batch_size: usize) {
+        let sample_count = inputs.dim().0;
+
+        for epoch in 0..epochs {
+            let mut indices: Vec<usize> = (0..sample_count).collect();
+            indices.shuffle(&mut thread_rng());
+
+            let shuffled_inputs = inputs.select(Axis(0), &indices);
+            let shuffled_targets = targets.select_rows(&indices);
+
+            let mut epoch_loss = 0.0;
+            let mut batch_count = 0;
+
+            for batch_start in (0..sample_count).step_by(batch_size) {
+                let batch_end = (batch_start + batch_size).min(sample_count);
+
+                let batch_inputs = shuffled_inputs.slice(s![batch_start..batch_end, ..]).to_owned();
+                let batch_targets = shuffled_targets.slice_rows(batch_start, batch_end);
+
+                let predicted = self.forward(batch_inputs, true);
+                let batch_loss = self.loss.calculate(predicted.clone(), batch_targets.clone());
+
+                if !batch_loss.is_finite() {
+                    if let Some(on_error) = &mut self.on_error {
+                        on_error(batch_loss);
+                    }
+                }
+
+                self.propagate_loss_gradient(predicted, batch_targets);
+                self.apply_gradients();
+
+                epoch_loss += batch_loss;
+                batch_count += 1;
+            }
+
+            if let Some(on_epoch) = &mut self.on_epoch {
+                on_epoch(epoch, epoch_loss / batch_count as f64);
+            }
+        }
+    }
+
+    /**
+     * Trains the network against sparse integer class targets for `epochs` passes over the data,
+     * shuffling the rows each epoch and running mini-batches of `batch_size` samples.
+     */
+    pub fn fit(&mut self, inputs: Array2<f64>, targets: Array1<usize>, epochs: usize, batch_size: usize) {
+        self.fit_with_target_data(inputs, SparseLossTargetData::new_sparse(targets), epochs, batch_size);
+    }
+
+    /**
+     * One-hot-target counterpart of `fit`.
+     */
+    pub fn fit_onehot(&mut self, inputs: Array2<f64>, targets: Array2<f64>, epochs: usize, batch_size: usize) {
+        self.fit_with_target_data(inputs, OneHotLossTargetData::new_onehot(targets), epochs, batch_size);
+    }
+
+    pub fn predict(&mut self, inputs: Array2<f64>) -> Array2<f64> {
+        self.forward(inputs, false)
+    }
+
+    /**
+     * Serializes each layer's weights, biases, activation kind, and dimensions to `path` as JSON.
+     * The optimizer and loss function are training configuration, not model state, so they are
+     * not persisted.
+     */
+    pub fn save(&self, path: &str) {
+        let snapshots: Vec<LayerSnapshot> = self.layers.iter().map(|layer| layer.snapshot()).collect();
+        let json = serde_json::to_string_pretty(&snapshots).expect("layer snapshots should serialize to JSON");
+
+        std::fs::write(path, json).expect("File should be writable");
+    }
+
+    /**
+     * Loads weights/biases/activation back into this network's existing layers, in order. The
+     * layer count and shapes must already match what was saved; returns
+     * `LayerArrayError::IncorrectDimension` if any layer's weights don't match the snapshot.
+     */
+    pub fn load(&mut self, path: &str) -> Result<(), LayerArrayError> {
+        let json = std::fs::read_to_string(path).expect("File should open read only");
+        let snapshots: Vec<LayerSnapshot> = serde_json::from_str(&json).expect("File should be proper JSON");
+
+        if snapshots.len() != self.layers.len() {
+            return Err(LayerArrayError::IncorrectDimension(
+                format!(
+                    "This network has {} layers, but the snapshot has {}.",
+                    self.layers.len(),
+                    snapshots.len()
+                ).to_string()
+            ));
+        }
+
+        for (layer, snapshot) in self.layers.iter_mut().zip(snapshots) {
+            layer.load_snapshot(snapshot)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod sequential_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use ndarray::{array, Array2};
+
+    use crate::{activation_function::LayerActivation, loss_function::{LossFunction, LossFunctionType}, optimizer::Sgd};
+
+    use super::{DenseLayer, DropoutLayer, Initialization, Layer, Sequential};
+
+    #[test]
+    fn fit_reduces_loss_over_epochs() {
+        let inputs = array![
+            [0., 0.],
+            [1., 1.],
+            [0., 1.],
+            [1., 0.]
+        ];
+        let targets = array![0, 0, 1, 1];
+
+        let mut network = Sequential::new(Box::new(Sgd::new(0.1)), LossFunction::new(LossFunctionType::CrossEntropy));
+        network.add(Box::new(DenseLayer::new(2, 4, LayerActivation::ReLU)));
+        network.add(Box::new(DenseLayer::new(4, 2, LayerActivation::Softmax)));
+
+        let losses = Rc::new(RefCell::new(Vec::new()));
+        let losses_handle = losses.clone();
+        network.on_epoch(move |_epoch, loss| losses_handle.borrow_mut().push(loss));
+        network.fit(inputs, targets, 20, 4);
+
+        let losses = losses.borrow();
+        assert_eq!(losses.len(), 20);
+        assert!(losses.last().unwrap() <= &losses[0]);
+    }
+
+    #[test]
+    fn save_and_load_round_trips_weights() {
+        let mut original = Sequential::new(Box::new(Sgd::new(0.1)), LossFunction::new(LossFunctionType::CrossEntropy));
+        original.add(Box::new(DenseLayer::new(2, 4, LayerActivation::ReLU)));
+        original.add(Box::new(DenseLayer::new(4, 2, LayerActivation::Softmax)));
+
+        let path = std::env::temp_dir().join("neuralnet-rs-sequential-save-test.json");
+        let path = path.to_str().unwrap();
+        original.save(path);
+
+        let original_output = original.predict(array![[1., 2.]]);
+
+        let mut restored = Sequential::new(Box::new(Sgd::new(0.1)), LossFunction::new(LossFunctionType::CrossEntropy));
+        restored.add(Box::new(DenseLayer::new(2, 4, LayerActivation::ReLU)));
+        restored.add(Box::new(DenseLayer::new(4, 2, LayerActivation::Softmax)));
+        restored.load(path).unwrap();
+
+        let restored_output = restored.predict(array![[1., 2.]]);
+
+        assert_eq!(original_output, restored_output);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn load_rejects_mismatched_shapes() {
+        let mut small = Sequential::new(Box::new(Sgd::new(0.1)), LossFunction::new(LossFunctionType::CrossEntropy));
+        small.add(Box::new(DenseLayer::new(2, 2, LayerActivation::Softmax)));
+
+        let path = std::env::temp_dir().join("neuralnet-rs-sequential-mismatch-test.json");
+        let path = path.to_str().unwrap();
+        small.save(path);
+
+        let mut large = Sequential::new(Box::new(Sgd::new(0.1)), LossFunction::new(LossFunctionType::CrossEntropy));
+        large.add(Box::new(DenseLayer::new(3, 2, LayerActivation::Softmax)));
+
+        assert!(large.load(path).is_err());
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn dropout_is_a_no_op_at_inference() {
+        let mut dropout = DropoutLayer::new(0.5);
+        let inputs = array![[1., 2., 3., 4.]];
+
+        let output = dropout.forward(inputs.clone(), false);
+
+        assert_eq!(output, inputs);
+    }
+
+    #[test]
+    fn dropout_zeroes_or_scales_every_activation_during_training() {
+        let mut dropout = DropoutLayer::new(0.5);
+        let inputs = Array2::ones((4, 32));
+
+        let output = dropout.forward(inputs, true);
+
+        assert!(output.iter().all(|&x| x == 0.0 || (x - 2.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn dropout_backward_masks_gradients_the_same_way_as_forward() {
+        let mut dropout = DropoutLayer::new(0.5);
+        let inputs = Array2::ones((4, 32));
+
+        let output = dropout.forward(inputs, true);
+        let grad_input = dropout.backward(Array2::ones((4, 32)));
+
+        assert_eq!(output, grad_input);
+    }
+
+    #[test]
+    fn dense_layer_forward_and_backward_match_hand_computed_gradients() {
+        let mut layer = DenseLayer::new(2, 2, LayerActivation::Linear);
+        layer.weights = array![[1.0, 2.0], [3.0, 4.0]];
+        layer.biases = array![0.0, 0.0];
+
+        let output = layer.forward(array![[1.0, 2.0]], true);
+        assert_eq!(output, array![[7.0, 10.0]]);
+
+        let grad_input = layer.backward(array![[1.0, 1.0]]);
+        assert_eq!(grad_input, array![[3.0, 7.0]]);
+
+        let params = layer.params_mut().unwrap();
+        assert_eq!(params.weight_grad, &array![[1.0, 1.0], [2.0, 2.0]]);
+        assert_eq!(params.bias_grad, &array![1.0, 1.0]);
+    }
+
+    #[test]
+    fn he_init_draws_larger_weights_than_xavier() {
+        let he_layer = DenseLayer::with_init(256, 64, LayerActivation::ReLU, Initialization::He);
+        let xavier_layer = DenseLayer::with_init(256, 64, LayerActivation::ReLU, Initialization::Xavier);
+
+        let mean_square = |weights: &Array2<f64>| weights.mapv(|w| w * w).mean().unwrap();
+
+        // He's variance 2/fan_in is double Xavier's 1/fan_in, so with this many samples the
+        // empirical mean square of He's weights should be clearly the larger of the two.
+        assert!(mean_square(&he_layer.weights) > mean_square(&xavier_layer.weights));
+    }
+}